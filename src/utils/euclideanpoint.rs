@@ -0,0 +1,115 @@
+use std::cmp;
+
+use super::color::RGBColor;
+use crate::utils::*;
+use cmp::Ordering;
+use nalgebra::*;
+use point::{Point, Wall};
+use serde::Deserialize;
+
+/// Struct representing a point in ordinary flat (Euclidean) space.
+/// Wrapper for nalgebra's Point2.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EuclideanPoint(pub Point2<f64>);
+
+impl EuclideanPoint {
+    /// Constructs the point given x and y.
+    pub fn new(x: f64, y: f64) -> EuclideanPoint {
+        EuclideanPoint {
+            0: Point2::<f64>::new(x, y),
+        }
+    }
+
+    /// Rotates the point around the origin. Ordinary rotation.
+    pub fn rotate(&mut self, angle: f64) {
+        let rot = Rotation2::new(angle);
+        self.0 = rot.transform_point(&self.0);
+    }
+
+    /// Translates the point by `(x, y)`. Ordinary vector addition.
+    pub fn translate(&mut self, x: f64, y: f64) {
+        self.0 = Point2::new(self.0[0] + x, self.0[1] + y);
+    }
+}
+
+impl point::Point for EuclideanPoint {
+    /// Return the ordinary Euclidean dot product of the two vectors provided.
+    fn minkowski_dot(a: &EuclideanPoint, b: &EuclideanPoint) -> f64 {
+        a.0[0] * b.0[0] + a.0[1] * b.0[1]
+    }
+
+    /// Distance to origin in the Euclidean metric.
+    fn distance_to_origin(&self) -> f64 {
+        self.0.coords.norm()
+    }
+
+    /// New point at 0, 0.
+    fn new_at_origin() -> Self {
+        EuclideanPoint::new(0., 0.)
+    }
+
+    /// Distance to another point in the Euclidean metric.
+    fn distance_to(&self, to: &Self) -> f64 {
+        (self.0 - to.0).norm()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EuclideanWall {
+    pub beginning: EuclideanPoint,
+    pub end: EuclideanPoint,
+    pub color: RGBColor,
+}
+
+impl Wall for EuclideanWall {
+    /// Distance to the closest end of the wall.
+    fn distance_to_closest_point(&self) -> f64 {
+        let dist_a = self.beginning.distance_to_origin();
+        let dist_b = self.end.distance_to_origin();
+
+        dist_a.min(dist_b)
+    }
+
+    /// Casts a ray from the origin along the given screen `angle` and
+    /// returns the Euclidean distance to this wall's segment, if the ray
+    /// hits it in front of the origin: the ordinary ray/segment
+    /// intersection test.
+    fn intersection(&self, angle: f64) -> Option<f64> {
+        let direction = Vector2::new(angle.cos(), angle.sin());
+        let edge = self.end.0 - self.beginning.0;
+
+        // Solve t * direction - s * edge == beginning for the ray
+        // parameter t and the position s along the segment.
+        let m = Matrix2::new(direction[0], -edge[0], direction[1], -edge[1]);
+        let ts = m.lu().solve(&self.beginning.0.coords)?;
+        let (t, s) = (ts[0], ts[1]);
+
+        if t > 0. && (0. ..=1.).contains(&s) {
+            Some(t)
+        } else {
+            None
+        }
+    }
+}
+
+impl Ord for EuclideanWall {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+impl Eq for EuclideanWall {}
+
+impl PartialEq for EuclideanWall {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance_to_closest_point()
+            .eq(&other.distance_to_closest_point())
+    }
+}
+
+impl PartialOrd for EuclideanWall {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.distance_to_closest_point()
+            .partial_cmp(&other.distance_to_closest_point())
+    }
+}
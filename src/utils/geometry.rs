@@ -0,0 +1,66 @@
+use super::euclideanpoint::EuclideanWall;
+use super::hyperpoint::HyperWall;
+use super::spherepoint::SphereWall;
+use crate::utils::*;
+use point::Wall;
+
+/// Selects which geometry model the raycaster renders.
+///
+/// Mirrors how HyperRogue treats hyperbolic, Euclidean and spherical
+/// space uniformly: the same camera and wall list can be interpreted
+/// under any of the three by swapping the underlying point/wall types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeometryMode {
+    /// Minkowski hyperboloid model, see `Hyperpoint`/`HyperWall`.
+    Hyperbolic,
+    /// Ordinary flat Euclidean space, see `EuclideanPoint`/`EuclideanWall`.
+    Euclidean,
+    /// Unit sphere model, see `SpherePoint`/`SphereWall`.
+    Spherical,
+}
+
+impl Default for GeometryMode {
+    fn default() -> Self {
+        GeometryMode::Hyperbolic
+    }
+}
+
+/// A level's walls under one `GeometryMode`, selected at runtime.
+pub enum GeometryWalls {
+    Hyperbolic(Vec<HyperWall>),
+    Euclidean(Vec<EuclideanWall>),
+    Spherical(Vec<SphereWall>),
+}
+
+impl GeometryWalls {
+    /// The `GeometryMode` this wall list is being rendered under.
+    pub fn mode(&self) -> GeometryMode {
+        match self {
+            GeometryWalls::Hyperbolic(_) => GeometryMode::Hyperbolic,
+            GeometryWalls::Euclidean(_) => GeometryMode::Euclidean,
+            GeometryWalls::Spherical(_) => GeometryMode::Spherical,
+        }
+    }
+
+    /// Casts a ray at `angle` against every wall and returns the
+    /// distance to the closest hit, dispatching to the concrete
+    /// point/wall types for this `GeometryMode` without the caller
+    /// needing to know which geometry is active.
+    pub fn closest_intersection(&self, angle: f64) -> Option<f64> {
+        match self {
+            GeometryWalls::Hyperbolic(walls) => closest(walls, angle),
+            GeometryWalls::Euclidean(walls) => closest(walls, angle),
+            GeometryWalls::Spherical(walls) => closest(walls, angle),
+        }
+    }
+}
+
+fn closest<W: Wall>(walls: &[W], angle: f64) -> Option<f64> {
+    walls
+        .iter()
+        .filter_map(|wall| wall.intersection(angle))
+        .fold(None, |closest, distance| match closest {
+            Some(closest) if closest <= distance => Some(closest),
+            _ => Some(distance),
+        })
+}
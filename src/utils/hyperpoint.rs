@@ -69,6 +69,41 @@ impl Hyperpoint {
         let translation = translation1 * translation2;
         self.0 = translation * &self.0;
     }
+
+    /// Performs an exact geodesic translation by `distance` in `direction_angle`, unlike `translate`.
+    pub fn push(&mut self, direction_angle: f64, distance: f64) {
+        self.rotate(-direction_angle);
+
+        let coshd = f64::cosh(distance);
+        let sinhd = f64::sinh(distance);
+        let boost = Matrix3::new(coshd, 0., sinhd, 0., 1., 0., sinhd, 0., coshd);
+        self.0 = boost * &self.0;
+
+        self.rotate(direction_angle);
+    }
+
+    /// Converts to horocyclic (binary-tiling) coordinates `(w, t)`. See `from_horocyclic` for the inverse.
+    pub fn to_horocyclic(&self) -> (f64, f64) {
+        let x = self.0[0];
+        let y = self.0[1];
+        let z = self.0[2];
+
+        let w = -f64::ln(z - x);
+        let t = y * f64::exp(w);
+
+        (w, t)
+    }
+
+    /// Reconstructs a hyperboloid point from horocyclic (binary-tiling) coordinates `(w, t)`.
+    pub fn from_horocyclic(w: f64, t: f64) -> Hyperpoint {
+        // From w = -ln(z - x) and the hyperboloid constraint x^2 + y^2 -
+        // z^2 == -1, combined: z - x == e^-w and z + x == (1 + y^2) * e^w,
+        // so x == sinh(w) + t^2 * e^-w / 2, not bare sinh(w).
+        let x = f64::sinh(w) + t * t * f64::exp(-w) / 2.;
+        let y = t * f64::exp(-w);
+
+        Hyperpoint::new(x, y)
+    }
 }
 
 impl point::Point for Hyperpoint {
@@ -105,15 +140,16 @@ pub struct HyperWall {
 }
 
 impl HyperWall {
-    /// Unused, but left for potential use.
     /// Intersection of a plane which goes through origin
     /// with the hyperboloid creates a geodesic.
     ///
-    /// Potentially can be used for ditching the conversion to
-    /// Poincare disk model for raycasting.
-    fn _find_plane_through_2_points_and_origin(p1: Hyperpoint, p2: Hyperpoint) -> (f64, f64, f64) {
+    /// Used by `intersection` to ditch the conversion to the Poincare
+    /// disk model for raycasting: the returned normal `n` satisfies
+    /// `n . p == 0` for every point `p` on the geodesic through `p1`
+    /// and `p2`.
+    fn find_plane_through_2_points_and_origin(p1: &Hyperpoint, p2: &Hyperpoint) -> (f64, f64, f64) {
         let (ax, ay, az): (f64, f64, f64) = (p1.0[0], p1.0[1], p1.0[2]);
-        let (bx, by, bz): (f64, f64, f64) = (p1.0[0], p1.0[1], p1.0[2]);
+        let (bx, by, bz): (f64, f64, f64) = (p2.0[0], p2.0[1], p2.0[2]);
         let (cx, cy, cz) = (0., 0., 0.);
 
         let a = (by - ay) * (cz - az) - (cy - ay) * (bz - az);
@@ -133,8 +169,64 @@ impl Wall for HyperWall {
         dist_a.min(dist_b)
     }
 
-    fn intersection(&self, _angle: f64) -> Option<f64> {
-        todo!()
+    /// Casts a ray from the camera at the hyperboloid origin along the
+    /// given screen `angle` and returns the hyperbolic distance to this
+    /// wall's geodesic, if the ray hits the wall's segment in front of
+    /// the camera.
+    ///
+    /// The viewing geodesic and the wall's geodesic are each the
+    /// intersection of the hyperboloid with a plane through the
+    /// Minkowski origin; intersecting the two planes (the cross product
+    /// of their normals) gives the single candidate point shared by both
+    /// geodesics, which is then normalized back onto the hyperboloid.
+    fn intersection(&self, angle: f64) -> Option<f64> {
+        let camera = Hyperpoint::new_at_origin();
+        let view_direction = Hyperpoint::new_with_z(angle.cos(), angle.sin(), 0.);
+
+        let (vx, vy, vz) =
+            HyperWall::find_plane_through_2_points_and_origin(&camera, &view_direction);
+        let (wx, wy, wz) =
+            HyperWall::find_plane_through_2_points_and_origin(&self.beginning, &self.end);
+
+        // The line shared by both planes through the origin.
+        let line = Vector3::new(vy * wz - vz * wy, vz * wx - vx * wz, vx * wy - vy * wx);
+
+        // Normalize the line's direction onto the hyperboloid sheet
+        // x^2 + y^2 - z^2 == -1, z > 0.
+        let scale_sq = line[2] * line[2] - line[0] * line[0] - line[1] * line[1];
+        if scale_sq <= 0. {
+            // The two geodesics don't meet on this sheet.
+            return None;
+        }
+        let mut scale = scale_sq.sqrt().recip();
+        if line[2] * scale < 0. {
+            scale = -scale;
+        }
+        let point = Hyperpoint::new_with_z(line[0] * scale, line[1] * scale, line[2] * scale);
+
+        // The point must lie between the wall's endpoints: any point on
+        // the geodesic through `beginning` and `end` can be written as a
+        // linear combination of the two, and falls on the segment
+        // between them exactly when both coefficients share a sign.
+        let coeffs = Matrix2::new(
+            self.beginning.0[0],
+            self.end.0[0],
+            self.beginning.0[1],
+            self.end.0[1],
+        )
+        .lu()
+        .solve(&Vector2::new(point.0[0], point.0[1]))?;
+        if coeffs[0] * coeffs[1] < 0. {
+            return None;
+        }
+
+        // And it must be in front of the camera, on the ray cast towards
+        // `angle` rather than behind the origin.
+        if point.0[0] * angle.cos() + point.0[1] * angle.sin() <= 0. {
+            return None;
+        }
+
+        Some(point.distance_to_origin())
     }
 }
 
@@ -169,3 +261,39 @@ impl PartialOrd for HyperWall {
             .partial_cmp(&other.distance_to_closest_point())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn horocyclic_round_trip() {
+        for &(x, y) in &[(0.4, 0.9), (0., 0.), (1.5, -2.3), (-0.7, 0.2), (3.1, 4.8)] {
+            let point = Hyperpoint::new(x, y);
+            let (w, t) = point.to_horocyclic();
+            let round_tripped = Hyperpoint::from_horocyclic(w, t);
+
+            assert!((round_tripped.0[0] - point.0[0]).abs() < 1e-9);
+            assert!((round_tripped.0[1] - point.0[1]).abs() < 1e-9);
+            assert!((round_tripped.0[2] - point.0[2]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn hyperwall_intersection_hits_and_misses() {
+        let wall = HyperWall {
+            beginning: Hyperpoint::new(1., -1.),
+            end: Hyperpoint::new(1., 1.),
+            color: RGBColor { r: 0, g: 0, b: 0 },
+        };
+
+        // Facing straight at the wall (along +x) should hit it.
+        assert!(wall.intersection(0.).is_some());
+
+        // Facing straight away from it should miss.
+        assert!(wall.intersection(std::f64::consts::PI).is_none());
+
+        // Facing perpendicular to it should miss too.
+        assert!(wall.intersection(std::f64::consts::FRAC_PI_2).is_none());
+    }
+}
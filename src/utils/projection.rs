@@ -0,0 +1,90 @@
+use super::hyperpoint::Hyperpoint;
+
+/// Maps a `Hyperpoint` on the hyperboloid to 2D plane coordinates, ready
+/// for screen-space rasterization. The Poincare and Klein models land
+/// inside the unit disk; the Gans and azimuthal equidistant models are
+/// unbounded.
+///
+/// Implementations correspond to the different ways the hyperbolic
+/// plane can be projected, the way HyperRogue exposes several `pmodel`
+/// choices for the same underlying geometry.
+pub trait ProjectionModel {
+    /// Projects a point on the hyperboloid onto the plane.
+    fn project(&self, point: &Hyperpoint) -> (f64, f64);
+}
+
+/// The Poincare disk model: conformal, geodesics are circular arcs
+/// meeting the boundary at right angles.
+pub struct PoincareProjection;
+
+impl ProjectionModel for PoincareProjection {
+    fn project(&self, point: &Hyperpoint) -> (f64, f64) {
+        let (x, y, z) = (point.0[0], point.0[1], point.0[2]);
+        (x / (1. + z), y / (1. + z))
+    }
+}
+
+/// The Beltrami-Klein model: geodesics are straight chords, which makes
+/// wall rasterization cheaper and lets `HyperWall::intersection` be
+/// validated by eye, since walls render as straight lines.
+pub struct KleinProjection;
+
+impl ProjectionModel for KleinProjection {
+    fn project(&self, point: &Hyperpoint) -> (f64, f64) {
+        let (x, y, z) = (point.0[0], point.0[1], point.0[2]);
+        (x / z, y / z)
+    }
+}
+
+/// The Gans model: an orthographic "drop" projection, `(x, y)` with no
+/// rescaling.
+pub struct GansProjection;
+
+impl ProjectionModel for GansProjection {
+    fn project(&self, point: &Hyperpoint) -> (f64, f64) {
+        (point.0[0], point.0[1])
+    }
+}
+
+/// The azimuthal equidistant model: distance from the origin is
+/// rendered true to scale along every ray out of the center.
+pub struct AzimuthalEquidistantProjection;
+
+impl ProjectionModel for AzimuthalEquidistantProjection {
+    fn project(&self, point: &Hyperpoint) -> (f64, f64) {
+        let (x, y, z) = (point.0[0], point.0[1], point.0[2]);
+        let planar_radius = (x * x + y * y).sqrt();
+        if planar_radius == 0. {
+            return (0., 0.);
+        }
+        let scale = z.acosh() / planar_radius;
+        (x * scale, y * scale)
+    }
+}
+
+/// Selects which `ProjectionModel` the renderer draws walls under, so
+/// the same `HyperWall` list can be viewed through different models at
+/// runtime.
+pub enum ProjectionMode {
+    Poincare(PoincareProjection),
+    Klein(KleinProjection),
+    Gans(GansProjection),
+    AzimuthalEquidistant(AzimuthalEquidistantProjection),
+}
+
+impl Default for ProjectionMode {
+    fn default() -> Self {
+        ProjectionMode::Poincare(PoincareProjection)
+    }
+}
+
+impl ProjectionModel for ProjectionMode {
+    fn project(&self, point: &Hyperpoint) -> (f64, f64) {
+        match self {
+            ProjectionMode::Poincare(p) => p.project(point),
+            ProjectionMode::Klein(p) => p.project(point),
+            ProjectionMode::Gans(p) => p.project(point),
+            ProjectionMode::AzimuthalEquidistant(p) => p.project(point),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::fmt;
+
+use super::color::RGBColor;
+use super::hyperpoint::{HyperWall, Hyperpoint};
+use super::poincarepoint::{PoincarePoint, PoincareWall};
+
+/// A point light source in the scene, placed in the Poincare disk like a
+/// wall endpoint and converted onto the hyperboloid the same way.
+#[derive(Clone, Debug)]
+pub struct Light {
+    pub position: Hyperpoint,
+    pub color: RGBColor,
+}
+
+/// A fully populated scene, as described by a scene file: camera pose,
+/// render configuration, lights and the colored walls to draw.
+#[derive(Clone, Debug)]
+pub struct Scene {
+    pub eye: (f64, f64, f64),
+    pub viewdir: (f64, f64, f64),
+    pub updir: (f64, f64, f64),
+    pub hfov: f64,
+    pub imsize: (usize, usize),
+    pub bkgcolor: RGBColor,
+    pub lights: Vec<Light>,
+    pub walls: Vec<HyperWall>,
+}
+
+impl Default for Scene {
+    fn default() -> Self {
+        Scene {
+            eye: (0., 0., 0.),
+            viewdir: (0., 0., -1.),
+            updir: (0., 1., 0.),
+            hfov: 90.,
+            imsize: (640, 480),
+            bkgcolor: RGBColor { r: 0, g: 0, b: 0 },
+            lights: Vec::new(),
+            walls: Vec::new(),
+        }
+    }
+}
+
+/// An error encountered while parsing a scene file.
+#[derive(Clone, Debug)]
+pub struct SceneParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for SceneParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for SceneParseError {}
+
+/// Parses a text scene description into a `Scene`.
+///
+/// The format is a keyword per line, analogous to the
+/// `eye/viewdir/updir/hfov/imsize/bkgcolor/mtlcolor` scenes used by
+/// text-driven raytracers:
+///
+/// ```text
+/// eye 0 0 0
+/// viewdir 0 0 -1
+/// updir 0 1 0
+/// hfov 90
+/// imsize 640 480
+/// bkgcolor 0 0 0
+/// light 0 0 255 255 255
+/// mtlcolor 255 0 0
+/// wall -0.5 0 0.5 0
+/// ```
+///
+/// Each `light` line gives the `x y` Poincare disk position of a point
+/// light followed by its `RGBColor`. Each `wall` line gives the `x y`
+/// coordinates of the two Poincare disk endpoints of a wall, colored
+/// with the most recently seen `mtlcolor`; it is converted to a
+/// `HyperWall` through the existing `From<PoincareWall> for HyperWall`
+/// path.
+pub fn parse_scene(text: &str) -> Result<Scene, SceneParseError> {
+    let mut scene = Scene::default();
+    let mut mtlcolor: Option<RGBColor> = None;
+
+    for (i, raw_line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let line = raw_line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut tokens = line.split_whitespace();
+        let keyword = tokens.next().unwrap();
+        let rest: Vec<&str> = tokens.collect();
+
+        let err = |message: String| SceneParseError {
+            line: line_no,
+            message,
+        };
+        let parse_f64 = |s: &str| -> Result<f64, SceneParseError> {
+            s.parse::<f64>()
+                .map_err(|_| err(format!("expected a number, got '{}'", s)))
+        };
+        let parse_usize = |s: &str| -> Result<usize, SceneParseError> {
+            s.parse::<usize>()
+                .map_err(|_| err(format!("expected a non-negative integer, got '{}'", s)))
+        };
+        let parse_triple = |rest: &[&str]| -> Result<(f64, f64, f64), SceneParseError> {
+            if rest.len() != 3 {
+                return Err(err(format!("expected 3 numbers, got {}", rest.len())));
+            }
+            Ok((
+                parse_f64(rest[0])?,
+                parse_f64(rest[1])?,
+                parse_f64(rest[2])?,
+            ))
+        };
+        let parse_color = |rest: &[&str]| -> Result<RGBColor, SceneParseError> {
+            if rest.len() != 3 {
+                return Err(err(format!("expected 3 color components, got {}", rest.len())));
+            }
+            Ok(RGBColor {
+                r: parse_f64(rest[0])? as u8,
+                g: parse_f64(rest[1])? as u8,
+                b: parse_f64(rest[2])? as u8,
+            })
+        };
+
+        match keyword {
+            "eye" => scene.eye = parse_triple(&rest)?,
+            "viewdir" => scene.viewdir = parse_triple(&rest)?,
+            "updir" => scene.updir = parse_triple(&rest)?,
+            "hfov" => {
+                if rest.len() != 1 {
+                    return Err(err(format!("expected 1 number, got {}", rest.len())));
+                }
+                scene.hfov = parse_f64(rest[0])?;
+            }
+            "imsize" => {
+                if rest.len() != 2 {
+                    return Err(err(format!("expected width and height, got {}", rest.len())));
+                }
+                scene.imsize = (parse_usize(rest[0])?, parse_usize(rest[1])?);
+            }
+            "bkgcolor" => scene.bkgcolor = parse_color(&rest)?,
+            "light" => {
+                if rest.len() != 5 {
+                    return Err(err(format!(
+                        "expected 5 numbers (position and color), got {}",
+                        rest.len()
+                    )));
+                }
+                let position: Hyperpoint =
+                    PoincarePoint(nalgebra::Point2::new(parse_f64(rest[0])?, parse_f64(rest[1])?))
+                        .into();
+                scene.lights.push(Light {
+                    position,
+                    color: parse_color(&rest[2..])?,
+                });
+            }
+            "mtlcolor" => mtlcolor = Some(parse_color(&rest)?),
+            "wall" => {
+                if rest.len() != 4 {
+                    return Err(err(format!(
+                        "expected 4 numbers (two endpoints), got {}",
+                        rest.len()
+                    )));
+                }
+                let color = mtlcolor
+                    .clone()
+                    .ok_or_else(|| err("wall has no preceding mtlcolor".to_string()))?;
+                let wall = PoincareWall {
+                    beginning: PoincarePoint(nalgebra::Point2::new(
+                        parse_f64(rest[0])?,
+                        parse_f64(rest[1])?,
+                    )),
+                    end: PoincarePoint(nalgebra::Point2::new(
+                        parse_f64(rest[2])?,
+                        parse_f64(rest[3])?,
+                    )),
+                    color,
+                };
+                scene.walls.push(wall.into());
+            }
+            other => return Err(err(format!("unknown keyword '{}'", other))),
+        }
+    }
+
+    Ok(scene)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_full_scene() {
+        let text = "eye 0 0 0\nviewdir 0 0 -1\nupdir 0 1 0\nhfov 90\nimsize 640 480\n\
+            bkgcolor 10 20 30\nlight 0.1 0.2 255 255 255\nmtlcolor 200 0 0\nwall -0.5 0 0.5 0\n";
+
+        let scene = parse_scene(text).unwrap();
+
+        assert_eq!(scene.eye, (0., 0., 0.));
+        assert_eq!(scene.hfov, 90.);
+        assert_eq!(scene.imsize, (640, 480));
+        assert_eq!(scene.bkgcolor.r, 10);
+        assert_eq!(scene.lights.len(), 1);
+        assert_eq!(scene.lights[0].color.r, 255);
+        assert_eq!(scene.walls.len(), 1);
+        assert_eq!(scene.walls[0].color.r, 200);
+    }
+
+    #[test]
+    fn rejects_unknown_keyword() {
+        assert!(parse_scene("bogus 1 2 3").is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_argument_count() {
+        assert!(parse_scene("hfov 1 2").is_err());
+    }
+
+    #[test]
+    fn rejects_wall_without_mtlcolor() {
+        assert!(parse_scene("wall -1 0 1 0").is_err());
+    }
+}
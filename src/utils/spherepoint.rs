@@ -0,0 +1,237 @@
+use std::cmp;
+
+use super::color::RGBColor;
+use crate::utils::*;
+use cmp::Ordering;
+use nalgebra::*;
+use point::{Point, Wall};
+use serde::Deserialize;
+
+/// Struct representing a point on the unit sphere,
+/// i.e. the elliptic geometry model.
+/// Wrapper for nalgebra's Point3.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpherePoint(pub Point3<f64>);
+
+impl SpherePoint {
+    /// Constructs the point given all coordinates.
+    /// Does not check whether the point lies on the sphere.
+    pub fn new_with_z(x: f64, y: f64, z: f64) -> SpherePoint {
+        SpherePoint {
+            0: Point3::<f64>::new(x, y, z),
+        }
+    }
+
+    /// Constructs the point given x and y.
+    /// Calculates z so it lies on the unit sphere.
+    pub fn new(x: f64, y: f64) -> SpherePoint {
+        let z = (1.0 - x.powi(2) - y.powi(2)).sqrt();
+        SpherePoint {
+            0: Point3::<f64>::new(x, y, z),
+        }
+    }
+
+    /// Rotates the point around the z axis at origin. Ordinary rotation.
+    pub fn rotate(&mut self, angle: f64) {
+        let rot = Rotation3::from_axis_angle(
+            &Unit::new_normalize(Vector3::<f64>::new(0.0, 0.0, 1.0)),
+            angle,
+        );
+        self.0 = rot.transform_point(&self.0);
+    }
+
+    /// Performs the equivalent of translation in the spherical model,
+    /// ordinary rotation around the x and y axes.
+    pub fn translate(&mut self, x: f64, y: f64) {
+        let rotation1 = Rotation3::from_axis_angle(
+            &Unit::new_normalize(Vector3::<f64>::new(1.0, 0.0, 0.0)),
+            x,
+        );
+        let rotation2 = Rotation3::from_axis_angle(
+            &Unit::new_normalize(Vector3::<f64>::new(0.0, 1.0, 0.0)),
+            -y,
+        );
+
+        self.0 = rotation1.transform_point(&rotation2.transform_point(&self.0));
+    }
+}
+
+impl point::Point for SpherePoint {
+    /// Return the ordinary Euclidean dot product of the two vectors provided.
+    /// Unlike the Minkowski hyperboloid, no coordinate is time-like.
+    fn minkowski_dot(a: &SpherePoint, b: &SpherePoint) -> f64 {
+        a.0[0] * b.0[0] + a.0[1] * b.0[1] + a.0[2] * b.0[2]
+    }
+
+    /// Distance to origin (the point (0, 0, 1)) in the spherical metric.
+    fn distance_to_origin(&self) -> f64 {
+        let dot: f64 = self.0[2];
+        dot.acos()
+    }
+
+    /// New point at 0, 0, 1.
+    fn new_at_origin() -> Self {
+        SpherePoint::new_with_z(0., 0., 1.)
+    }
+
+    /// Distance to another point in the spherical metric.
+    fn distance_to(&self, to: &Self) -> f64 {
+        let dot: f64 = self.0[2] * to.0[2] + self.0[1] * to.0[1] + self.0[0] * to.0[0];
+        dot.acos()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SphereWall {
+    pub beginning: SpherePoint,
+    pub end: SpherePoint,
+    pub color: RGBColor,
+}
+
+impl Wall for SphereWall {
+    /// Distance to the closest end of the wall.
+    fn distance_to_closest_point(&self) -> f64 {
+        let dist_a = self.beginning.distance_to_origin();
+        let dist_b = self.end.distance_to_origin();
+
+        dist_a.min(dist_b)
+    }
+
+    /// Casts a ray from the camera at the sphere's origin along the given
+    /// screen `angle` and returns the spherical distance to this wall's
+    /// great circle, mirroring `HyperWall::intersection`: a great circle
+    /// is the intersection of the sphere with a plane through its
+    /// ordinary (ambient) origin, so its normal `n` satisfies the
+    /// ordinary dot product `n . p == 0` for every point `p` on it -
+    /// unlike the hyperboloid, no sign flip is needed since the metric
+    /// here is the plain Euclidean one.
+    fn intersection(&self, angle: f64) -> Option<f64> {
+        let camera = SpherePoint::new_at_origin();
+        let view_direction = SpherePoint::new_with_z(angle.cos(), angle.sin(), 0.);
+
+        let n_view = camera.0.coords.cross(&view_direction.0.coords);
+        let n_wall = self.beginning.0.coords.cross(&self.end.0.coords);
+
+        // The line shared by both planes through the origin.
+        let line = n_view.cross(&n_wall);
+
+        // Normalize the line's direction onto the unit sphere.
+        let norm = line.norm();
+        if norm == 0. {
+            // The two great circles coincide or are parallel.
+            return None;
+        }
+        let direction = line / norm;
+
+        // Two great circles meet at an antipodal pair of points, and the
+        // between-endpoints test below can't tell them apart (a point
+        // and its antipode give it the same answer), so try both signs
+        // and keep whichever one is actually in front of the camera.
+        for sign in [1., -1.] {
+            let point = SpherePoint::new_with_z(
+                direction[0] * sign,
+                direction[1] * sign,
+                direction[2] * sign,
+            );
+
+            // The point must lie between the wall's endpoints: any point
+            // on the great circle through `beginning` and `end` can be
+            // written as a linear combination of the two, and falls on
+            // the segment between them exactly when both coefficients
+            // share a sign.
+            let coeffs = match Matrix2::new(
+                self.beginning.0[0],
+                self.end.0[0],
+                self.beginning.0[1],
+                self.end.0[1],
+            )
+            .lu()
+            .solve(&Vector2::new(point.0[0], point.0[1]))
+            {
+                Some(coeffs) => coeffs,
+                None => continue,
+            };
+            if coeffs[0] * coeffs[1] < 0. {
+                continue;
+            }
+
+            // And it must be in front of the camera, on the ray cast
+            // towards `angle` rather than behind the origin or on the
+            // far side of the sphere.
+            if point.0[0] * angle.cos() + point.0[1] * angle.sin() <= 0. {
+                continue;
+            }
+
+            return Some(point.distance_to_origin());
+        }
+
+        None
+    }
+}
+
+impl Ord for SphereWall {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+impl Eq for SphereWall {}
+
+impl PartialEq for SphereWall {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance_to_closest_point()
+            .eq(&other.distance_to_closest_point())
+    }
+}
+
+impl PartialOrd for SphereWall {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.distance_to_closest_point()
+            .partial_cmp(&other.distance_to_closest_point())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::TAU;
+
+    #[test]
+    fn intersection_is_independent_of_endpoint_order() {
+        let wall = SphereWall {
+            beginning: SpherePoint::new(0.3, 0.2),
+            end: SpherePoint::new(0.5, -0.1),
+            color: RGBColor { r: 0, g: 0, b: 0 },
+        };
+        let swapped = SphereWall {
+            beginning: wall.end.clone(),
+            end: wall.beginning.clone(),
+            color: wall.color.clone(),
+        };
+
+        let samples = 720;
+        let mut hits = 0;
+        for i in 0..samples {
+            let angle = TAU * (i as f64) / (samples as f64);
+            let hit = wall.intersection(angle);
+            let swapped_hit = swapped.intersection(angle);
+
+            match (hit, swapped_hit) {
+                (Some(a), Some(b)) => assert!((a - b).abs() < 1e-9),
+                (None, None) => {}
+                _ => panic!(
+                    "angle {} disagreed depending on endpoint order: {:?} vs {:?}",
+                    angle, hit, swapped_hit
+                ),
+            }
+            if hit.is_some() {
+                hits += 1;
+            }
+        }
+
+        // The wall is a short arc in front of the camera, so some but
+        // not all sampled angles should hit it.
+        assert!(hits > 0, "wall should be visible from some angles");
+        assert!(hits < samples, "wall should not be visible from every angle");
+    }
+}